@@ -1,5 +1,5 @@
 import { describe, it, expect } from "vitest";
-import { resolveTurn, initChampionState, isTeamEliminated } from "../combat";
+import { resolveTurn, initChampionState, isTeamEliminated, determineWinner } from "../combat";
 
 describe("initChampionState", () => {
   it("initializes correctly for each champion", () => {
@@ -136,6 +136,7 @@ describe("resolveTurn", () => {
     const boulder = opponentChampions.find((c) => c.id === 1)!;
     // Burn should have 2 turns left (3 applied, 1 ticked)
     expect(boulder.burnTurns).toBe(2);
+    expect(boulder.burnSourceId).toBe(0);
   });
 
   it("KO prevents second attack", () => {
@@ -182,4 +183,70 @@ describe("resolveTurn", () => {
     const atkDebuff = inferno.buffs.find((b) => b.type === "attack" && b.isDebuff);
     expect(atkDebuff).toBeDefined();
   });
+
+  it("credits burn ticks to the source and caps overkill in a double elimination", () => {
+    // Inferno (id 0, SPD 16) at 5 HP vs Phoenix (id 8, SPD 17) burning from
+    // an earlier Scorch at 3 HP. Phoenix's Blaze KOs Inferno first, then the
+    // burn tick KOs Phoenix in the same round.
+    const myChamps = [initChampionState(0)];
+    myChamps[0].currentHp = 5;
+    myChamps[0].totalDamageDealt = 3;
+    const oppChamps = [initChampionState(8)];
+    oppChamps[0].currentHp = 3;
+    oppChamps[0].burnTurns = 1;
+    oppChamps[0].burnSourceId = 0;
+
+    const { myChampions, opponentChampions } = resolveTurn(
+      myChamps,
+      oppChamps,
+      { championId: 0, abilityIndex: 0 }, // Eruption (never lands)
+      { championId: 8, abilityIndex: 0 }, // Blaze (74 dmg)
+    );
+
+    expect(isTeamEliminated(myChampions)).toBe(true);
+    expect(isTeamEliminated(opponentChampions)).toBe(true);
+    // Blaze only removed Inferno's last 5 HP; the burn tick removed Phoenix's last 3
+    expect(opponentChampions[0].totalDamageDealt).toBe(5);
+    expect(myChampions[0].totalDamageDealt).toBe(6);
+    expect(determineWinner(myChampions, opponentChampions)).toBe("me");
+    expect(determineWinner(opponentChampions, myChampions)).toBe("opponent");
+  });
+});
+
+describe("determineWinner", () => {
+  function koTeam(ids: number[], damage: number[]) {
+    return ids.map((id, i) => {
+      const c = initChampionState(id);
+      c.isKO = true;
+      c.currentHp = 0;
+      c.totalDamageDealt = damage[i];
+      return c;
+    });
+  }
+
+  it("returns null while both teams have a champion standing", () => {
+    const mine = [initChampionState(0)];
+    const theirs = [initChampionState(1)];
+    expect(determineWinner(mine, theirs)).toBeNull();
+  });
+
+  it("awards the win to the side still standing", () => {
+    const alive = [initChampionState(0)];
+    const fallen = koTeam([1], [50]);
+    expect(determineWinner(alive, fallen)).toBe("me");
+    expect(determineWinner(fallen, alive)).toBe("opponent");
+  });
+
+  it("breaks simultaneous elimination by total damage dealt", () => {
+    const mine = koTeam([0, 2], [60, 40]); // 100
+    const theirs = koTeam([1, 3], [70, 20]); // 90
+    expect(determineWinner(mine, theirs)).toBe("me");
+    expect(determineWinner(theirs, mine)).toBe("opponent");
+  });
+
+  it("is a draw only when total damage is exactly tied", () => {
+    const mine = koTeam([0, 2], [50, 50]);
+    const theirs = koTeam([1, 3], [30, 70]);
+    expect(determineWinner(mine, theirs)).toBe("draw");
+  });
 });
@@ -11,6 +11,7 @@ function makeState(championId: number): ChampionState {
     maxHp: champ.hp,
     buffs: [],
     burnTurns: 0,
+    burnSourceId: null,
     isKO: false,
     totalDamageDealt: 0,
   };
@@ -58,9 +58,9 @@ export function resolveTurn(
     executeAction(second, first, !firstIsMe, events);
   }
 
-  // Process burn ticks for both sides (if alive)
-  processBurnTick(myState, events);
-  processBurnTick(oppState, events);
+  // Process burn ticks for both sides (if alive), crediting the burn's source
+  processBurnTick(myState, oppStates, events);
+  processBurnTick(oppState, myStates, events);
 
   // Tick down buff durations
   tickBuffs(myState);
@@ -94,8 +94,9 @@ function executeAction(
         ability,
         actor.state.buffs,
       );
+      // Credit only the HP actually removed, so overkill doesn't count
+      actor.state.totalDamageDealt += Math.min(damage, target.state.currentHp);
       target.state.currentHp = Math.max(0, target.state.currentHp - damage);
-      actor.state.totalDamageDealt += damage;
 
       events.push({
         type: "attack",
@@ -123,8 +124,9 @@ function executeAction(
         ability,
         actor.state.buffs,
       );
+      // Credit only the HP actually removed, so overkill doesn't count
+      actor.state.totalDamageDealt += Math.min(damage, target.state.currentHp);
       target.state.currentHp = Math.max(0, target.state.currentHp - damage);
-      actor.state.totalDamageDealt += damage;
 
       events.push({
         type: "attack",
@@ -144,6 +146,7 @@ function executeAction(
       // Apply burn
       if (ability.appliesBurn && ability.duration && !target.state.isKO) {
         target.state.burnTurns = ability.duration;
+        target.state.burnSourceId = actor.champion.id;
         events.push({ type: "burn_applied", targetId: target.champion.id, duration: ability.duration });
       }
       break;
@@ -205,13 +208,16 @@ function executeAction(
   }
 }
 
-function processBurnTick(state: ChampionState, events: TurnEvent[]): void {
+function processBurnTick(state: ChampionState, opposingStates: ChampionState[], events: TurnEvent[]): void {
   if (state.burnTurns > 0 && !state.isKO) {
     const burnDamage = calculateBurnDamage(state);
+    const source = opposingStates.find((s) => s.id === state.burnSourceId);
+    if (source) source.totalDamageDealt += Math.min(burnDamage, state.currentHp);
     state.currentHp = Math.max(0, state.currentHp - burnDamage);
     events.push({ type: "burn_tick", championId: state.id, damage: burnDamage });
 
     state.burnTurns--;
+    if (state.burnTurns === 0) state.burnSourceId = null;
     if (state.currentHp === 0) {
       state.isKO = true;
       events.push({ type: "ko", championId: state.id });
@@ -236,6 +242,7 @@ export function initChampionState(championId: number): ChampionState {
     maxHp: champ.hp,
     buffs: [],
     burnTurns: 0,
+    burnSourceId: null,
     isKO: false,
     totalDamageDealt: 0,
   };
@@ -247,3 +254,28 @@ export function initChampionState(championId: number): ChampionState {
 export function isTeamEliminated(team: ChampionState[]): boolean {
   return team.every((c) => c.isKO);
 }
+
+/**
+ * Decide the match outcome once at least one side is eliminated.
+ * If both teams fall in the same round, the side whose champions dealt more
+ * total damage wins; only an exact damage tie is a draw. Damage totals include
+ * burn ticks (credited to the champion that applied the burn) and exclude
+ * overkill beyond the target's remaining HP.
+ * Returns null while both teams still have a champion standing.
+ */
+export function determineWinner(
+  myTeam: ChampionState[],
+  opponentTeam: ChampionState[],
+): "me" | "opponent" | "draw" | null {
+  const myEliminated = isTeamEliminated(myTeam);
+  const oppEliminated = isTeamEliminated(opponentTeam);
+
+  if (!myEliminated && !oppEliminated) return null;
+  if (!myEliminated) return "me";
+  if (!oppEliminated) return "opponent";
+
+  const myDamage = myTeam.reduce((sum, c) => sum + c.totalDamageDealt, 0);
+  const oppDamage = opponentTeam.reduce((sum, c) => sum + c.totalDamageDealt, 0);
+  if (myDamage === oppDamage) return "draw";
+  return myDamage > oppDamage ? "me" : "opponent";
+}
@@ -20,7 +20,7 @@ import { useCallback, useEffect, useRef } from "react";
 import { useGameStore, type BattlePhase } from "../store/gameStore";
 import { useCommitReveal } from "./useCommitReveal";
 import { encodeMove, decodeMove } from "../engine/codec";
-import { resolveTurn, isTeamEliminated } from "../engine/combat";
+import { resolveTurn, determineWinner } from "../engine/combat";
 import { playSfx } from "../audio/audioManager";
 import type { TurnAction, TurnRecord } from "../types";
 
@@ -185,18 +185,10 @@ export function useCombatTurn(): UseCombatTurnReturn {
     setBattlePhase("animating");
 
     // Check for game-over conditions after a brief delay for animations
-    const myEliminated = isTeamEliminated(result.myChampions);
-    const oppEliminated = isTeamEliminated(result.opponentChampions);
-
-    if (myEliminated || oppEliminated) {
-      // Determine winner
-      const winner: "me" | "opponent" | "draw" =
-        myEliminated && oppEliminated
-          ? "draw"
-          : oppEliminated
-            ? "me"
-            : "opponent";
+    // (simultaneous elimination is decided by total damage dealt)
+    const winner = determineWinner(result.myChampions, result.opponentChampions);
 
+    if (winner) {
       // Determine MVP: the champion with the most total damage dealt
       const allChampions = [...result.myChampions, ...result.opponentChampions];
       const mvp = allChampions.reduce(
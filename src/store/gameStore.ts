@@ -209,6 +209,7 @@ export const useGameStore = create<GameStore>((set) => ({
           maxHp: getChampionHp(id),
           buffs: [],
           burnTurns: 0,
+          burnSourceId: null,
           isKO: false,
           totalDamageDealt: 0,
         })),
@@ -218,6 +219,7 @@ export const useGameStore = create<GameStore>((set) => ({
           maxHp: getChampionHp(id),
           buffs: [],
           burnTurns: 0,
+          burnSourceId: null,
           isKO: false,
           totalDamageDealt: 0,
         })),
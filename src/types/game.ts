@@ -42,6 +42,8 @@ export interface ChampionState {
   maxHp: number;
   buffs: Buff[];
   burnTurns: number;
+  /** Champion that applied the active burn, credited with its tick damage */
+  burnSourceId: number | null;
   isKO: boolean;
   totalDamageDealt: number;
 }